@@ -32,6 +32,26 @@ unsafe impl<T: ?Sized, U: ?Sized> As<Box<U>> for Box<T>
 where T: As<U>
 {}
 
+unsafe impl<T, U> As<Vec<U>> for Vec<T>
+where T: As<U>,
+{}
+
+unsafe impl<T: ?Sized, U: ?Sized> As<std::rc::Rc<U>> for std::rc::Rc<T>
+where T: As<U>,
+{}
+
+unsafe impl<T: ?Sized, U: ?Sized> As<std::rc::Weak<U>> for std::rc::Weak<T>
+where T: As<U>,
+{}
+
+unsafe impl<T: ?Sized, U: ?Sized> As<std::sync::Arc<U>> for std::sync::Arc<T>
+where T: As<U>,
+{}
+
+unsafe impl<T: ?Sized, U: ?Sized> As<std::sync::Weak<U>> for std::sync::Weak<T>
+where T: As<U>,
+{}
+
 unsafe impl As<[u8]> for str
 {}
 
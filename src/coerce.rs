@@ -103,6 +103,98 @@ where T: Coerce<U>
     }
 }
 
+unsafe impl<T, U> Coerce<Vec<U>> for Vec<T>
+where T: Coerce<U>
+{
+    unsafe fn coerce(self) -> Vec<U> {
+        assert_layout_eq!(T, U);
+
+        let mut this = ManuallyDrop::new(self);
+
+        let ptr: *mut U = T::coerce_mut_ptr(this.as_mut_ptr());
+        let len = this.len();
+        let cap = this.capacity();
+
+        Vec::from_raw_parts(ptr, len, cap)
+    }
+
+    fn coerce_ptr(this: *const Self) -> *const Vec<U> {
+        this as *const Vec<U>
+    }
+}
+
+unsafe impl<T: ?Sized, U: ?Sized> Coerce<std::rc::Rc<U>> for std::rc::Rc<T>
+where T: Coerce<U>
+{
+    unsafe fn coerce(self) -> std::rc::Rc<U> {
+        assert_ptr_layout_eq!(T, U);
+
+        let t_ptr: *const T = std::rc::Rc::into_raw(self);
+        let u_ptr: *const U = T::coerce_ptr(t_ptr);
+
+        std::rc::Rc::from_raw(u_ptr)
+    }
+
+    fn coerce_ptr(this: *const Self) -> *const std::rc::Rc<U> {
+        this as *const std::rc::Rc<U>
+    }
+}
+
+/// Coercing the pointee never touches the refcount header, so a `Weak` stays
+/// tied to the same allocation and upgrades still observe the live strong count.
+unsafe impl<T: ?Sized, U: ?Sized> Coerce<std::rc::Weak<U>> for std::rc::Weak<T>
+where T: Coerce<U>
+{
+    unsafe fn coerce(self) -> std::rc::Weak<U> {
+        assert_ptr_layout_eq!(T, U);
+
+        let t_ptr: *const T = std::rc::Weak::into_raw(self);
+        let u_ptr: *const U = T::coerce_ptr(t_ptr);
+
+        std::rc::Weak::from_raw(u_ptr)
+    }
+
+    fn coerce_ptr(this: *const Self) -> *const std::rc::Weak<U> {
+        this as *const std::rc::Weak<U>
+    }
+}
+
+unsafe impl<T: ?Sized, U: ?Sized> Coerce<std::sync::Arc<U>> for std::sync::Arc<T>
+where T: Coerce<U>
+{
+    unsafe fn coerce(self) -> std::sync::Arc<U> {
+        assert_ptr_layout_eq!(T, U);
+
+        let t_ptr: *const T = std::sync::Arc::into_raw(self);
+        let u_ptr: *const U = T::coerce_ptr(t_ptr);
+
+        std::sync::Arc::from_raw(u_ptr)
+    }
+
+    fn coerce_ptr(this: *const Self) -> *const std::sync::Arc<U> {
+        this as *const std::sync::Arc<U>
+    }
+}
+
+/// Coercing the pointee never touches the refcount header, so a `Weak` stays
+/// tied to the same allocation and upgrades still observe the live strong count.
+unsafe impl<T: ?Sized, U: ?Sized> Coerce<std::sync::Weak<U>> for std::sync::Weak<T>
+where T: Coerce<U>
+{
+    unsafe fn coerce(self) -> std::sync::Weak<U> {
+        assert_ptr_layout_eq!(T, U);
+
+        let t_ptr: *const T = std::sync::Weak::into_raw(self);
+        let u_ptr: *const U = T::coerce_ptr(t_ptr);
+
+        std::sync::Weak::from_raw(u_ptr)
+    }
+
+    fn coerce_ptr(this: *const Self) -> *const std::sync::Weak<U> {
+        this as *const std::sync::Weak<U>
+    }
+}
+
 unsafe impl Coerce<str> for [u8] {
     #[inline(always)]
     fn coerce_ptr(this: *const Self) -> *const str {
@@ -212,6 +304,47 @@ mod tests {
         assert_eq!(boxed_nonzero, vec![NonZeroU64::new(1).unwrap(); 100].into_boxed_slice());
     }
 
+    #[test]
+    fn vec_coercion() {
+        let mut vec_u64: Vec<u64> = Vec::with_capacity(128);
+        vec_u64.extend(std::iter::repeat(1u64).take(100));
+
+        let cap = vec_u64.capacity();
+
+        let vec_nonzero: Vec<NonZeroU64> = unsafe { vec_u64.coerce() };
+
+        assert_eq!(vec_nonzero.capacity(), cap);
+        assert_eq!(vec_nonzero, vec![NonZeroU64::new(1).unwrap(); 100]);
+    }
+
+    #[test]
+    fn rc_coercion() {
+        use std::rc::Rc;
+
+        let rc_u64: Rc<u64> = Rc::new(1u64);
+        let weak_u64 = Rc::downgrade(&rc_u64);
+
+        let rc_nonzero: Rc<NonZeroU64> = unsafe { rc_u64.coerce() };
+        assert_eq!(*rc_nonzero, NonZeroU64::new(1).unwrap());
+
+        let weak_nonzero: std::rc::Weak<NonZeroU64> = unsafe { weak_u64.coerce() };
+        assert_eq!(*weak_nonzero.upgrade().unwrap(), NonZeroU64::new(1).unwrap());
+    }
+
+    #[test]
+    fn arc_coercion() {
+        use std::sync::Arc;
+
+        let arc_u64: Arc<u64> = Arc::new(1u64);
+        let weak_u64 = Arc::downgrade(&arc_u64);
+
+        let arc_nonzero: Arc<NonZeroU64> = unsafe { arc_u64.coerce() };
+        assert_eq!(*arc_nonzero, NonZeroU64::new(1).unwrap());
+
+        let weak_nonzero: std::sync::Weak<NonZeroU64> = unsafe { weak_u64.coerce() };
+        assert_eq!(*weak_nonzero.upgrade().unwrap(), NonZeroU64::new(1).unwrap());
+    }
+
     #[cfg(feature = "maybe_uninit")]
     #[test]
     fn maybe_uninit() {
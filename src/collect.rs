@@ -0,0 +1,132 @@
+use crate::{Coerce, Unsize};
+
+/// Builds on [`Unsize`] to gather many `Box<T>` values, each unsizing to a
+/// common `U`, into a single collection — e.g. several `Box<Concrete>` into a
+/// `Vec<Box<dyn Trait>>`, or a run of `Box<[u8; N]>` into a `Vec<Box<[u8]>>`.
+/// All items of one call share the same source type `T`, and each call builds
+/// a new collection.
+///
+/// Each source box is unsized exactly once as it's inserted, reusing its
+/// allocation; the only new allocation is the backing collection itself.
+///
+/// This only covers genuine unsizing targets (`T: Unsize<U>`); for same-size
+/// `Box<T> -> Box<U>` conversions (`T: Coerce<U>`), use
+/// [`CollectCoercedSameSize`] instead. The two can't live on the same trait:
+/// a blanket impl bounded on `Unsize` and one bounded on `Coerce`, both fully
+/// generic over `T`/`U`, would be overlapping impls of `CollectCoerced<U> for
+/// I` as far as coherence is concerned, even though no concrete type
+/// implements both bounds today.
+pub trait CollectCoerced<U: ?Sized> {
+    /// Unsizes every item and collects the results into `C`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use coercion::CollectCoerced;
+    ///
+    /// let boxed_arrays: Vec<Box<[u8; 4]>> = vec![Box::new([1, 2, 3, 4]), Box::new([5, 6, 7, 8])];
+    ///
+    /// let boxed_slices: Vec<Box<[u8]>> = boxed_arrays.collect_coerced();
+    /// assert_eq!(&*boxed_slices[1], &[5, 6, 7, 8]);
+    /// ```
+    fn collect_coerced<C>(self) -> C
+        where C: Default + Extend<Box<U>>;
+}
+
+impl<I, T, U> CollectCoerced<U> for I
+where
+    I: IntoIterator<Item = Box<T>>,
+    T: Unsize<U>,
+    U: ?Sized,
+{
+    fn collect_coerced<C>(self) -> C
+        where C: Default + Extend<Box<U>>
+    {
+        let mut out = C::default();
+        out.extend(self.into_iter().map(T::unsize_box));
+        out
+    }
+}
+
+/// The same-size counterpart to [`CollectCoerced`], for `Box<T> -> Box<U>`
+/// conversions where `T: Coerce<U>` rather than `T: Unsize<U>` — e.g.
+/// gathering `Box<u8>` sources into a single `Vec<Box<bool>>`.
+///
+/// Kept as a separate trait so its blanket impl doesn't overlap with
+/// [`CollectCoerced`]'s; see that trait's docs for why.
+pub trait CollectCoercedSameSize<U: ?Sized> {
+    /// Coerces every item and collects the results into `C`.
+    ///
+    /// # Safety
+    ///
+    /// Same contract as [`Coerce::coerce`]: every item's bit pattern must be
+    /// valid for `U`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use coercion::CollectCoercedSameSize;
+    ///
+    /// let boxed_bytes: Vec<Box<u8>> = vec![Box::new(1u8), Box::new(0u8)];
+    ///
+    /// // Safe because 1 and 0 are both valid bools.
+    /// let boxed_bools: Vec<Box<bool>> = unsafe { boxed_bytes.collect_coerced_same_size() };
+    /// assert_eq!(*boxed_bools[0], true);
+    /// assert_eq!(*boxed_bools[1], false);
+    /// ```
+    unsafe fn collect_coerced_same_size<C>(self) -> C
+        where C: Default + Extend<Box<U>>;
+}
+
+impl<I, T, U> CollectCoercedSameSize<U> for I
+where
+    I: IntoIterator<Item = Box<T>>,
+    T: Coerce<U>,
+    U: ?Sized,
+{
+    unsafe fn collect_coerced_same_size<C>(self) -> C
+        where C: Default + Extend<Box<U>>
+    {
+        let mut out = C::default();
+        out.extend(self.into_iter().map(|b| b.coerce()));
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{Greet, Hello};
+
+    use std::collections::VecDeque;
+
+    #[test]
+    fn collect_boxed_arrays_into_boxed_slices() {
+        let boxed_arrays: Vec<Box<[u8; 2]>> = vec![Box::new([1, 2]), Box::new([3, 4])];
+
+        let boxed_slices: Vec<Box<[u8]>> = boxed_arrays.collect_coerced();
+
+        assert_eq!(&*boxed_slices[0], &[1, 2]);
+        assert_eq!(&*boxed_slices[1], &[3, 4]);
+    }
+
+    #[test]
+    fn collect_boxed_concrete_into_boxed_dyn_trait() {
+        let boxed_hellos: Vec<Box<Hello>> = vec![Box::new(Hello), Box::new(Hello)];
+
+        let boxed_greets: VecDeque<Box<dyn Greet>> = boxed_hellos.collect_coerced();
+
+        assert_eq!(boxed_greets.len(), 2);
+        assert_eq!(boxed_greets[0].greet(), "hello");
+    }
+
+    #[test]
+    fn collect_boxed_bytes_into_boxed_bools_same_size() {
+        let boxed_bytes: Vec<Box<u8>> = vec![Box::new(1u8), Box::new(0u8)];
+
+        let boxed_bools: Vec<Box<bool>> = unsafe { boxed_bytes.collect_coerced_same_size() };
+
+        assert_eq!(*boxed_bools[0], true);
+        assert_eq!(*boxed_bools[1], false);
+    }
+}
@@ -39,6 +39,15 @@ pub use self::coerce::Coerce;
 mod as_;
 pub use self::as_::As;
 
+mod unsize;
+pub use self::unsize::Unsize;
+
+mod try_coerce;
+pub use self::try_coerce::{TryCoerce, InvalidBoolByte, TryCoerceZeroError};
+
+mod collect;
+pub use self::collect::{CollectCoerced, CollectCoercedSameSize};
+
 /// Implements `Coerce` for sized types.
 #[macro_export]
 macro_rules! unsafe_impl_coerce {
@@ -125,3 +134,21 @@ mod tests {
         assert_eq!(2 + 2, 4);
     }
 }
+
+/// Shared `dyn Trait` unsizing fixture for `unsize` and `collect` tests.
+#[cfg(test)]
+pub(crate) mod test_util {
+    pub trait Greet {
+        fn greet(&self) -> &'static str;
+    }
+
+    pub struct Hello;
+
+    impl Greet for Hello {
+        fn greet(&self) -> &'static str {
+            "hello"
+        }
+    }
+
+    crate::unsafe_impl_unsize!(Hello => dyn Greet);
+}
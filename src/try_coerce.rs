@@ -0,0 +1,215 @@
+use core::fmt;
+use core::num::*;
+use core::str::{self, Utf8Error};
+
+use crate::Coerce;
+
+/// A validated, checked coercion, returning `Result` instead of requiring `unsafe`.
+///
+/// Where `Coerce` trusts the caller to guarantee the source bit pattern is valid
+/// for the target type, `TryCoerce` performs that validation itself and only
+/// calls the `Coerce` machinery once it has passed.
+pub trait TryCoerce<T: ?Sized>: Coerce<T> {
+    /// The error produced when the source value isn't valid for `T`.
+    type Error;
+
+    /// Performs the conversion on an owned, sized, value.
+    fn try_coerce(self) -> Result<T, Self::Error>
+        where Self: Sized, T: Sized
+    {
+        self.try_coerce_ref()?;
+
+        Ok(unsafe { self.coerce() })
+    }
+
+    /// Performs the conversion on a borrowed value, leaving it unmoved on failure.
+    fn try_coerce_ref(&self) -> Result<&T, Self::Error>;
+}
+
+/// The byte at `index` wasn't `0` or `1`, so it isn't a valid `bool`.
+#[derive(Debug, PartialEq, Eq)]
+pub struct InvalidBoolByte {
+    pub index: usize,
+}
+
+impl fmt::Display for InvalidBoolByte {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "byte at index {} is not a valid bool (0 or 1)", self.index)
+    }
+}
+
+/// The value was `0`, so it isn't valid for a `NonZero*` type.
+#[derive(Debug, PartialEq, Eq)]
+pub struct TryCoerceZeroError;
+
+impl fmt::Display for TryCoerceZeroError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "value is zero")
+    }
+}
+
+fn validate_bool_bytes(bytes: &[u8]) -> Result<(), InvalidBoolByte> {
+    for (index, &b) in bytes.iter().enumerate() {
+        if b > 1 {
+            return Err(InvalidBoolByte { index });
+        }
+    }
+    Ok(())
+}
+
+impl TryCoerce<str> for [u8] {
+    type Error = Utf8Error;
+
+    fn try_coerce_ref(&self) -> Result<&str, Utf8Error> {
+        str::from_utf8(self)
+    }
+}
+
+impl TryCoerce<[bool]> for [u8] {
+    type Error = InvalidBoolByte;
+
+    fn try_coerce_ref(&self) -> Result<&[bool], InvalidBoolByte> {
+        validate_bool_bytes(self)?;
+
+        unsafe {
+            Ok(&*<[u8] as Coerce<[bool]>>::coerce_ptr(self))
+        }
+    }
+}
+
+impl TryCoerce<Box<str>> for Box<[u8]> {
+    type Error = Utf8Error;
+
+    fn try_coerce(self) -> Result<Box<str>, Utf8Error> {
+        str::from_utf8(&self)?;
+
+        Ok(unsafe { self.coerce() })
+    }
+
+    fn try_coerce_ref(&self) -> Result<&Box<str>, Utf8Error> {
+        str::from_utf8(self)?;
+
+        Ok(unsafe { &*(self as *const Box<[u8]> as *const Box<str>) })
+    }
+}
+
+impl TryCoerce<Box<[bool]>> for Box<[u8]> {
+    type Error = InvalidBoolByte;
+
+    fn try_coerce(self) -> Result<Box<[bool]>, InvalidBoolByte> {
+        validate_bool_bytes(&self)?;
+
+        Ok(unsafe { self.coerce() })
+    }
+
+    fn try_coerce_ref(&self) -> Result<&Box<[bool]>, InvalidBoolByte> {
+        validate_bool_bytes(self)?;
+
+        Ok(unsafe { &*(self as *const Box<[u8]> as *const Box<[bool]>) })
+    }
+}
+
+macro_rules! impl_try_coerce_nonzero {
+    ($($t:ty => $nz:ty;)+) => {
+        $(
+            impl TryCoerce<$nz> for $t {
+                type Error = TryCoerceZeroError;
+
+                fn try_coerce_ref(&self) -> Result<&$nz, TryCoerceZeroError> {
+                    if *self == 0 {
+                        Err(TryCoerceZeroError)
+                    } else {
+                        Ok(unsafe { &*(self as *const $t as *const $nz) })
+                    }
+                }
+            }
+
+            impl TryCoerce<Box<$nz>> for Box<$t> {
+                type Error = TryCoerceZeroError;
+
+                fn try_coerce(self) -> Result<Box<$nz>, TryCoerceZeroError> {
+                    if *self == 0 {
+                        Err(TryCoerceZeroError)
+                    } else {
+                        Ok(unsafe { self.coerce() })
+                    }
+                }
+
+                fn try_coerce_ref(&self) -> Result<&Box<$nz>, TryCoerceZeroError> {
+                    if **self == 0 {
+                        Err(TryCoerceZeroError)
+                    } else {
+                        Ok(unsafe { &*(self as *const Box<$t> as *const Box<$nz>) })
+                    }
+                }
+            }
+        )+
+    }
+}
+
+impl_try_coerce_nonzero! {
+    u8 => NonZeroU8;
+    u16 => NonZeroU16;
+    u32 => NonZeroU32;
+    u64 => NonZeroU64;
+    u128 => NonZeroU128;
+    usize => NonZeroUsize;
+    i8 => NonZeroI8;
+    i16 => NonZeroI16;
+    i32 => NonZeroI32;
+    i64 => NonZeroI64;
+    i128 => NonZeroI128;
+    isize => NonZeroIsize;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bytes_to_str() {
+        let bytes: &[u8] = b"Hello World!";
+
+        let s: &str = bytes.try_coerce_ref().unwrap();
+        assert_eq!(s, "Hello World!");
+
+        let invalid: &[u8] = &[0xff, 0xfe];
+        assert!(TryCoerce::<str>::try_coerce_ref(invalid).is_err());
+    }
+
+    #[test]
+    fn boxed_bytes_to_str() {
+        let boxed: Box<[u8]> = Box::from(&b"Hello World!"[..]);
+        let boxed_str: Box<str> = boxed.try_coerce().unwrap();
+        assert_eq!(&*boxed_str, "Hello World!");
+
+        let invalid: Box<[u8]> = Box::from(&[0xff, 0xfe][..]);
+        assert!(TryCoerce::<Box<str>>::try_coerce(invalid).is_err());
+    }
+
+    #[test]
+    fn bytes_to_bools() {
+        let bytes: &[u8] = &[1, 0, 1, 0];
+        let bools: &[bool] = bytes.try_coerce_ref().unwrap();
+        assert_eq!(bools, &[true, false, true, false]);
+
+        let invalid: &[u8] = &[1, 0, 2, 0];
+        assert_eq!(TryCoerce::<[bool]>::try_coerce_ref(invalid), Err(InvalidBoolByte { index: 2 }));
+    }
+
+    #[test]
+    fn u32_to_nonzero() {
+        let n: NonZeroU32 = 1u32.try_coerce().unwrap();
+        assert_eq!(n.get(), 1);
+
+        assert_eq!(0u32.try_coerce(), Err(TryCoerceZeroError));
+    }
+
+    #[test]
+    fn boxed_u32_to_nonzero() {
+        let boxed_nonzero: Box<NonZeroU32> = Box::new(1u32).try_coerce().unwrap();
+        assert_eq!(boxed_nonzero.get(), 1);
+
+        assert!(Box::new(0u32).try_coerce().is_err());
+    }
+}
@@ -0,0 +1,146 @@
+/// Stable emulation of the (currently unstable) `CoerceUnsized` machinery.
+///
+/// The compiler already knows how to attach pointer metadata when casting a thin
+/// raw pointer to a fat one, e.g. `p as *const [T]` from `*const [T; N]`, or
+/// `p as *const dyn Trait` from `*const Concrete`. `Unsize` exposes that built-in
+/// cast as a trait so the rest of this crate can reuse it: an impl's body is
+/// always `p as *const U`, never `mem::transmute`, since a thin pointer can't be
+/// transmuted into a fat one.
+///
+/// Unlike `Coerce`, `Self` and `U` don't have the same size, so the pointer
+/// wrappers (`unsize_box`, `unsize_ref`, ...) are provided here as default
+/// methods rather than as `Coerce` impls.
+///
+/// # Safety
+///
+/// `unsize_ptr` must return a pointer with the same address as `p`; it may only
+/// attach metadata (length, vtable), never move or reinterpret the pointee's
+/// bytes.
+pub unsafe trait Unsize<U: ?Sized> {
+    /// Attaches `U`'s pointer metadata to `p`.
+    fn unsize_ptr(p: *const Self) -> *const U;
+
+    /// Unsizes a `Box<Self>` into a `Box<U>`, reusing the allocation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use coercion::Unsize;
+    ///
+    /// let boxed_array: Box<[u8; 4]> = Box::new([1, 2, 3, 4]);
+    /// let boxed_slice: Box<[u8]> = Unsize::unsize_box(boxed_array);
+    ///
+    /// assert_eq!(&boxed_slice[..], &[1, 2, 3, 4]);
+    /// ```
+    fn unsize_box(b: Box<Self>) -> Box<U>
+        where Self: Sized
+    {
+        let t_ptr: *mut Self = Box::into_raw(b);
+        let u_ptr = Self::unsize_ptr(t_ptr) as *mut U;
+
+        unsafe { Box::from_raw(u_ptr) }
+    }
+
+    /// Unsizes a `&Self` into a `&U`.
+    fn unsize_ref(r: &Self) -> &U
+        where Self: Sized
+    {
+        unsafe { &*Self::unsize_ptr(r) }
+    }
+
+    /// Unsizes a `&mut Self` into a `&mut U`.
+    fn unsize_mut(r: &mut Self) -> &mut U
+        where Self: Sized
+    {
+        unsafe { &mut *(Self::unsize_ptr(r) as *mut U) }
+    }
+
+    /// Unsizes a `*const Self` into a `*const U`.
+    fn unsize_const_ptr(p: *const Self) -> *const U
+        where Self: Sized
+    {
+        Self::unsize_ptr(p)
+    }
+
+    /// Unsizes a `*mut Self` into a `*mut U`.
+    fn unsize_mut_ptr(p: *mut Self) -> *mut U
+        where Self: Sized
+    {
+        Self::unsize_ptr(p) as *mut U
+    }
+}
+
+unsafe impl<T, const N: usize> Unsize<[T]> for [T; N] {
+    #[inline(always)]
+    fn unsize_ptr(p: *const Self) -> *const [T] {
+        p as *const [T]
+    }
+}
+
+/// Implements `Unsize<dyn Trait>` for a concrete type, via the built-in raw
+/// pointer unsizing cast.
+#[macro_export]
+macro_rules! unsafe_impl_unsize {
+    ( $t:ty => $u:ty ) => {
+        unsafe impl $crate::unsize::Unsize<$u> for $t {
+            #[inline(always)]
+            fn unsize_ptr(p: *const Self) -> *const $u {
+                p as *const $u
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{Greet, Hello};
+
+    #[test]
+    fn array_to_slice() {
+        let boxed_array: Box<[u8; 4]> = Box::new([1, 2, 3, 4]);
+
+        let boxed_slice: Box<[u8]> = Unsize::unsize_box(boxed_array);
+
+        assert_eq!(&boxed_slice[..], &[1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn concrete_to_dyn_trait() {
+        let boxed_hello: Box<Hello> = Box::new(Hello);
+
+        let boxed_greet: Box<dyn Greet> = Unsize::unsize_box(boxed_hello);
+
+        assert_eq!(boxed_greet.greet(), "hello");
+    }
+
+    #[test]
+    fn ref_array_to_slice() {
+        let array = [1u8, 2, 3, 4];
+        let array_ref: &[u8; 4] = &array;
+
+        let slice_ref: &[u8] = Unsize::unsize_ref(array_ref);
+
+        assert_eq!(slice_ref, &[1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn const_ptr_to_dyn_trait() {
+        let hello = Hello;
+        let hello_ptr: *const Hello = &hello;
+
+        let greet_ptr: *const dyn Greet = Unsize::unsize_const_ptr(hello_ptr);
+
+        assert_eq!(unsafe { &*greet_ptr }.greet(), "hello");
+    }
+
+    #[test]
+    fn mut_ptr_array_to_slice() {
+        let mut array = [1u8, 2, 3, 4];
+        let array_ptr: *mut [u8; 4] = &mut array;
+
+        let slice_ptr: *mut [u8] = Unsize::unsize_mut_ptr(array_ptr);
+
+        assert_eq!(unsafe { &*slice_ptr }, &[1, 2, 3, 4]);
+    }
+}